@@ -2,6 +2,7 @@ import { useState, useCallback, useEffect, useRef, useMemo, lazy, Suspense } fro
 import { listen } from '@tauri-apps/api/event';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 import { invoke } from '@tauri-apps/api/core';
+import { ask } from '@tauri-apps/plugin-dialog';
 import { copyFile, writeFile as writeBinaryFile, mkdir, exists } from './services/fs';
 import Editor from './components/Editor';
 import type { EditorHandle } from './components/Editor';
@@ -31,6 +32,7 @@ import TabBar from './components/TabBar';
 import BottomPanel, { type FileMeta } from './components/BottomPanel';
 import { useDragResize } from './hooks/useDragResize';
 import { syncSecretsFromCloud } from './services/apiSecrets';
+import { onShellApproval, shellApprove } from './services/shell';
 import { deployDemoHub, resolveVercelToken } from './services/publishVercel';
 import { useTabManager } from './hooks/useTabManager';
 import { useAutosave } from './hooks/useAutosave';
@@ -360,6 +362,20 @@ export default function App() {
     return () => { unlisten.then((fn) => fn()).catch(() => {}); };
   }, []);
 
+  // Agent commands that the workspace's shellPolicy wants confirmed (desktop).
+  useEffect(() => {
+    const unlisten = onShellApproval(async ({ request_id, cmd, cwd, reason }) => {
+      const approved = await ask(`The AI agent wants to run:\n\n${cmd}\n\nin ${cwd}\n(${reason})`, {
+        title: 'Run command?',
+        kind: 'warning',
+        okLabel: 'Run',
+        cancelLabel: 'Deny',
+      }).catch(() => false);
+      shellApprove(request_id, approved).catch(() => {});
+    });
+    return () => { unlisten.then((fn) => fn()).catch(() => {}); };
+  }, []);
+
   // ── OAuth deep-link callback (cafezin://auth/callback#access_token=...) ───
   // Delegated to useAuthSession. onAuthSuccess runs syncSecretsFromCloud and
   // dispatches cafezin:auth-updated so WorkspacePicker can refresh its state.
@@ -602,9 +618,9 @@ export default function App() {
   // Rebuilds files + fileTree and merges into workspace state.
   // Used after any operation that creates, deletes, or moves a file.
   const refreshWorkspace = useCallback(async (ws: Workspace) => {
-    const fileTree = await refreshFileTree(ws);
+    const { fileTree, fileTreeTruncated } = await refreshFileTree(ws);
     const files = flatMdFiles(fileTree);
-    setWorkspace((prev) => prev ? { ...prev, files, fileTree } : prev);
+    setWorkspace((prev) => prev ? { ...prev, files, fileTree, fileTreeTruncated } : prev);
   }, []);
 
   // ── File system watcher ────────────────────────────────────────────────────
@@ -32,6 +32,8 @@ function sanitizeWsPath(p: string): string {
 
 function friendlyGitError(err: unknown): string {
   const raw = String(err);
+  if (/certificate verification failed/i.test(raw))
+    return 'O certificado do servidor não é confiável. Verifique as configurações de TLS do workspace.';
   if (/Certificate|host key|hostkey|ssh.*23|code=-17/i.test(raw))
     return 'Não foi possível verificar o servidor SSH. Verifique sua conexão.';
   if (/Authentication|credential|auth/i.test(raw))
@@ -296,8 +298,12 @@ export default function MobileApp() {
     try {
       const token = getGitAccountToken(accountLabel) ?? undefined;
       const result = await gitPull(localPath, token);
-      const msg = result === 'up_to_date' ? 'Já está atualizado.' : 'Pull realizado com sucesso!';
-      toast({ message: msg, type: 'success' });
+      if (result === 'conflicts') {
+        toast({ message: 'Pull gerou conflitos — resolva-os antes de sincronizar.', type: 'error', duration: null });
+      } else {
+        const msg = result === 'up_to_date' ? 'Já está atualizado.' : 'Pull realizado com sucesso!';
+        toast({ message: msg, type: 'success' });
+      }
       void refreshWorkspace();
     } catch (err) {
       const errStr = String(err);
@@ -339,8 +345,34 @@ export default function MobileApp() {
       );
       const token = ws ? (getGitAccountToken(ws.gitAccountLabel) ?? undefined) : undefined;
       const result = await gitSync(workspace.path, token);
-      const msg = result === 'synced' ? 'Sincronizado com sucesso!' : 'Já estava atualizado.';
-      toast({ message: msg, type: 'success' });
+      switch (result.push) {
+        case 'pushed':
+          toast({ message: 'Sincronizado com sucesso!', type: 'success' });
+          break;
+        case 'no_remote':
+          toast({ message: 'Alterações salvas localmente (sem repositório remoto).', type: 'info' });
+          break;
+        case 'offline':
+          toast({ message: 'Sem conexão — alterações salvas localmente, envie mais tarde.', type: 'error', duration: null });
+          break;
+        case 'auth_failed':
+          toast({ message: 'Falha de autenticação — alterações não foram enviadas.', type: 'error', duration: null });
+          break;
+        case 'certificate_invalid':
+          toast({ message: friendlyGitError(result.push_error ?? 'certificate verification failed'), type: 'error', duration: null });
+          break;
+        case 'rejected_non_fast_forward':
+          toast({
+            message: result.pull === 'conflicts'
+              ? 'Conflitos ao mesclar com o remoto — resolva-os antes de sincronizar.'
+              : 'O remoto recusou o envio. Tente sincronizar novamente.',
+            type: 'error',
+            duration: null,
+          });
+          break;
+        default:
+          toast({ message: `Erro ao enviar: ${friendlyGitError(result.push_error ?? result.push)}`, type: 'error', duration: null });
+      }
       void refreshWorkspace();
     } catch (err) {
       toast({ message: `Erro ao sincronizar: ${friendlyGitError(err)}`, type: 'error', duration: null });
@@ -1,13 +1,15 @@
 /**
  * Tests for buildToolExecutor — the runtime implementation of all workspace tools.
  * The heavy canvas / network tools are lightly tested (no-editor guard, etc.);
- * the file-system tools get thorough coverage via the mocked @tauri-apps/plugin-fs.
+ * the file-system tools get thorough coverage via the mocked @tauri-apps/plugin-fs
+ * (and the mocked `workspace_tree` command for listing).
  */
 import { describe, it, expect, vi, beforeEach } from 'vitest';
-import type { DirEntry } from '@tauri-apps/plugin-fs';
 import * as tauriFs from '@tauri-apps/plugin-fs';
+import { invoke } from '@tauri-apps/api/core';
 import { buildToolExecutor } from '../utils/workspaceTools';
 import type { Editor } from 'tldraw';
+import type { FileTreeNode } from '../types';
 
 const WS_PATH = '/test/workspace';
 
@@ -28,6 +30,28 @@ function makeExecutor(
   );
 }
 
+/** Answer the Rust `workspace_tree` command with these files (relative paths). */
+function mockWorkspaceTree(paths: string[]) {
+  const nodes: FileTreeNode[] = [];
+  for (const path of paths) {
+    let siblings = nodes;
+    const parts = path.split('/');
+    parts.forEach((name, i) => {
+      const nodePath = parts.slice(0, i + 1).join('/');
+      const isDirectory = i < parts.length - 1;
+      let node = siblings.find((n) => n.path === nodePath);
+      if (!node) {
+        node = { name, path: nodePath, isDirectory, ...(isDirectory ? { children: [] } : {}) };
+        siblings.push(node);
+      }
+      siblings = node.children ?? [];
+    });
+  }
+  vi.mocked(invoke).mockImplementation(async (cmd) =>
+    cmd === 'workspace_tree' ? { nodes, truncated: false } : undefined,
+  );
+}
+
 beforeEach(() => {
   vi.clearAllMocks();
 });
@@ -35,10 +59,7 @@ beforeEach(() => {
 // ── list_workspace_files ──────────────────────────────────────────────────────
 describe('list_workspace_files', () => {
   it('returns all file paths when the workspace has files', async () => {
-    vi.mocked(tauriFs.readDir).mockResolvedValue([
-      { name: 'notes.md', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-      { name: 'ideas.md', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-    ]);
+    mockWorkspaceTree(['notes.md', 'ideas.md']);
     const exec = makeExecutor();
     const result = await exec('list_workspace_files', {});
     expect(result).toContain('notes.md');
@@ -47,27 +68,20 @@ describe('list_workspace_files', () => {
   });
 
   it('returns an empty-workspace message when the directory is empty', async () => {
-    vi.mocked(tauriFs.readDir).mockResolvedValue([]);
+    mockWorkspaceTree([]);
     const exec = makeExecutor();
     const result = await exec('list_workspace_files', {});
     expect(result).toBe('The workspace is empty.');
   });
 
-  it('skips ignored directories (node_modules, .git, target, etc.)', async () => {
-    vi.mocked(tauriFs.readDir).mockResolvedValue([
-      { name: 'node_modules', isDirectory: true, isFile: false, isSymlink: false } as DirEntry,
-      { name: 'src', isDirectory: true, isFile: false, isSymlink: false } as DirEntry,
-      { name: 'index.ts', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-    ]);
-    // readDir is also called recursively for 'src'; make it return empty
-    vi.mocked(tauriFs.readDir).mockResolvedValueOnce([
-      { name: 'node_modules', isDirectory: true, isFile: false, isSymlink: false } as DirEntry,
-      { name: 'src', isDirectory: true, isFile: false, isSymlink: false } as DirEntry,
-      { name: 'index.ts', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-    ]).mockResolvedValue([]);
+  it('lists nested files from workspace_tree, which applies the skip and ignore rules', async () => {
+    mockWorkspaceTree(['src/lib/util.ts', 'index.ts']);
     const exec = makeExecutor();
     const result = await exec('list_workspace_files', {});
-    expect(result).not.toContain('node_modules');
+    expect(invoke).toHaveBeenCalledWith('workspace_tree', { path: WS_PATH });
+    expect(result).toContain('src/lib/util.ts');
+    expect(result).toMatch(/2 file\(s\)/);
+    expect(tauriFs.readDir).not.toHaveBeenCalled();
   });
 });
 
@@ -181,9 +195,7 @@ describe('write_workspace_file', () => {
 // ── search_workspace ──────────────────────────────────────────────────────────
 describe('search_workspace', () => {
   it('returns a "no matches" message when nothing is found', async () => {
-    vi.mocked(tauriFs.readDir).mockResolvedValue([
-      { name: 'notes.md', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-    ]);
+    mockWorkspaceTree(['notes.md']);
     vi.mocked(tauriFs.readTextFile).mockResolvedValue('no match here');
     const exec = makeExecutor();
     const result = await exec('search_workspace', { query: 'unicorn' });
@@ -192,11 +204,7 @@ describe('search_workspace', () => {
   });
 
   it('returns matching lines with file context when a hit is found', async () => {
-    vi.mocked(tauriFs.readDir)
-      .mockResolvedValueOnce([
-        { name: 'readme.md', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-      ])
-      .mockResolvedValue([]);
+    mockWorkspaceTree(['readme.md']);
     vi.mocked(tauriFs.readTextFile).mockResolvedValue(
       'first line\nThis contains the TARGET keyword\nthird line',
     );
@@ -294,10 +302,7 @@ describe('write_workspace_file — canvas file rejection', () => {
 // ── search_workspace: canvas file exclusion ───────────────────────────────────
 describe('search_workspace — canvas file exclusion', () => {
   it('does not read .tldr.json files when searching', async () => {
-    vi.mocked(tauriFs.readDir).mockResolvedValue([
-      { name: 'notes.md', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-      { name: 'board.tldr.json', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-    ]);
+    mockWorkspaceTree(['notes.md', 'board.tldr.json']);
     vi.mocked(tauriFs.readTextFile).mockResolvedValue('TARGET keyword here');
     const exec = makeExecutor();
     await exec('search_workspace', { query: 'TARGET' });
@@ -308,9 +313,7 @@ describe('search_workspace — canvas file exclusion', () => {
   });
 
   it('reports zero text files when only canvas files exist', async () => {
-    vi.mocked(tauriFs.readDir).mockResolvedValue([
-      { name: 'board.tldr.json', isDirectory: false, isFile: true, isSymlink: false } as DirEntry,
-    ]);
+    mockWorkspaceTree(['board.tldr.json']);
     const exec = makeExecutor();
     const result = await exec('search_workspace', { query: 'anything' });
     expect(result).toContain('No matches found');
@@ -6,6 +6,7 @@ import { createFile, createCanvasFile, createFolder, refreshWorkspaceFiles, dele
 import SyncModal from './SyncModal';
 import ProjectSearchPanel from './ProjectSearchPanel';
 import type { Workspace, FileTreeNode, AIEditMark, SidebarButton } from '../types';
+import type { SyncOutcome } from '../services/syncConfig';
 import { loadWorkspaceSession, saveWorkspaceSession } from '../services/workspaceSession';
 import './Sidebar.css';
 
@@ -162,6 +163,7 @@ function TreeNodeItem({
           ) : (
             <span className="sidebar-tree-name">{node.name}</span>
           )}
+          {node.truncated && <span className="sidebar-tree-truncated" title="Not all of this folder is listed">…</span>}
           <span
             className="sidebar-tree-action"
             role="button"
@@ -446,8 +448,7 @@ export default function Sidebar({
     const dir_ = dirSet.has(oldPath);
     await updateFileReferences(workspace, workspace.fileTree, oldPath, newPath, dir_);
     await renameFile(workspace, oldPath, newPath);
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     // If the renamed file was the active one, notify parent
     if (activeFile === oldPath) onFileSelect(newPath);
     cancelRename();
@@ -476,8 +477,7 @@ export default function Sidebar({
       await deleteFile(workspace, p);
       onFileDeleted(p);
     }
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     setMultiSelected(new Set());
   }
 
@@ -516,8 +516,7 @@ export default function Sidebar({
     const newRel = destDir ? `${destDir}/${srcRel.split('/').pop()!}` : srcRel.split('/').pop()!;
     await updateFileReferences(workspace, workspace.fileTree, srcRel, newRel, isDir_);
     await moveFile(workspace, srcRel, destDir);
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     if (activeFile === srcRel || activeFile?.startsWith(srcRel + '/')) onFileSelect(newRel);
   }
 
@@ -529,24 +528,21 @@ export default function Sidebar({
       : `Delete "${name}"?\n\nThis will be tracked in git and can be reverted via Sync.`;
     if (!window.confirm(msg)) return;
     await deleteFile(workspace, relPath, isDir);
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     onFileDeleted(relPath);
     setContextMenu(null);
   }
 
   async function handleDuplicateFile(relPath: string) {
     const dupPath = await duplicateFile(workspace, relPath);
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     onFileSelect(dupPath);
     setContextMenu(null);
   }
 
   async function handleDuplicateFolder(relPath: string) {
     await duplicateFolder(workspace, relPath);
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     setContextMenu(null);
   }
 
@@ -559,8 +555,7 @@ export default function Sidebar({
     const newRel = destDir ? `${destDir}/${srcPath.split('/').pop()!}` : srcPath.split('/').pop()!;
     await updateFileReferences(workspace, workspace.fileTree, srcPath, newRel, isDir_);
     await moveFile(workspace, srcPath, destDir);
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     if (activeFile === srcPath || activeFile?.startsWith(srcPath + '/')) onFileSelect(newRel);
   }
 
@@ -685,8 +680,7 @@ export default function Sidebar({
 
     if (creatingKind === 'folder') {
       await createFolder(workspace, `${prefix}${baseName}`);
-      const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-      onWorkspaceChange({ ...workspace, files, fileTree });
+      onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
       // Auto-expand the new folder
       const newPath = `${prefix}${baseName}`;
       setExpandedDirs((prev) => { const s = new Set(prev); s.add(newPath); return s; });
@@ -706,8 +700,7 @@ export default function Sidebar({
       await createFile(workspace, relPath);
     }
 
-    const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-    onWorkspaceChange({ ...workspace, files, fileTree });
+    onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
     if (creatingIn) setExpandedDirs((prev) => { const s = new Set(prev); s.add(creatingIn!); return s; });
     cancelCreating();
     onFileSelect(relPath);
@@ -716,7 +709,11 @@ export default function Sidebar({
   async function handleSyncConfirm(message: string) {
     setSyncStatus('syncing');
     try {
-      await invoke('git_sync', { path: workspace.path, message });
+      const outcome = await invoke<SyncOutcome>('git_sync', { path: workspace.path, message });
+      if (outcome.push !== 'pushed' && outcome.push !== 'no_remote') {
+        // Committed locally but nothing reached the remote — surface it.
+        throw new Error(outcome.push_error ?? `Push failed: ${outcome.push}`);
+      }
       onSyncComplete();
       setSyncStatus('done');
     } catch (e) {
@@ -817,11 +814,15 @@ export default function Sidebar({
           const srcDir = srcRel.includes('/') ? srcRel.substring(0, srcRel.lastIndexOf('/')) : '';
           if (!srcDir) return; // already at root
           const newRel = await moveFile(workspace, srcRel, '');
-          const { files, fileTree } = await refreshWorkspaceFiles(workspace);
-          onWorkspaceChange({ ...workspace, files, fileTree });
+          onWorkspaceChange({ ...workspace, ...await refreshWorkspaceFiles(workspace) });
           if (activeFile === srcRel) onFileSelect(newRel);
         }}
       >
+        {workspace.fileTreeTruncated && (
+          <div className="sidebar-truncated" title="Folders marked … are not fully listed">
+            Some files are not shown — this workspace is too large or too deeply nested.
+          </div>
+        )}
         {workspace.fileTree.length === 0 && (
           <div className="sidebar-empty">No files yet</div>
         )}
@@ -129,7 +129,7 @@ export function useCanvasDrop({
     setWsImagesLoading(true);
     try {
       const WS_IMG_EXTS = new Set(['png', 'jpg', 'jpeg', 'gif', 'webp', 'svg', 'avif', 'bmp']);
-      const images = await walkFilesFlat(workspacePath, '', (_, ext) => WS_IMG_EXTS.has(ext));
+      const images = await walkFilesFlat(workspacePath, (_, ext) => WS_IMG_EXTS.has(ext));
       setWsImages(images);
     } catch {
       setWsImages([]);
@@ -7,14 +7,5 @@
 /** Folder name (inside the workspace root) used for app config/logs/marks. */
 export const CONFIG_DIR = 'cafezin';
 
-/**
- * Directory / file names that should be skipped when walking the workspace
- * file tree (listing, searching, etc.).
- */
-export const WORKSPACE_SKIP = new Set([
-  'node_modules',
-  '.git',
-  'cafezin',
-  'target',
-  '.DS_Store',
-]);
+// Names skipped when walking the workspace (node_modules, .git, …) are
+// WORKSPACE_TREE_SKIP in the Rust workspace_tree command.
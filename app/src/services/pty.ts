@@ -0,0 +1,57 @@
+/**
+ * pty — persistent terminal sessions for the bottom panel (desktop, non-App Store).
+ *
+ * `ptyOpen` starts the user's login shell on a pseudo-terminal in the Rust
+ * backend, so `cd`, env vars and interactive programs persist. Output (raw,
+ * escape sequences included — feed it to a terminal emulator) arrives through
+ * `pty:output` events; `pty:exit` fires when the shell ends.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+
+export interface PtyOutputEvent {
+  id: string
+  data: string
+}
+
+export interface PtyExitEvent {
+  id: string
+  exit_code: number | null
+}
+
+/**
+ * Open a session in `cwd` (must be inside `workspace` when given, otherwise
+ * inside the home directory). Resolves to its id.
+ * @param id  Optional id for its events, so none are missed; must not be open.
+ */
+export async function ptyOpen(
+  cwd: string,
+  cols: number,
+  rows: number,
+  opts: { workspace?: string; id?: string } = {},
+): Promise<string> {
+  return invoke<string>('pty_open', { cwd, cols, rows, workspace: opts.workspace ?? null, id: opts.id ?? null })
+}
+
+/** Send keystrokes or pasted text. */
+export async function ptyWrite(id: string, data: string): Promise<void> {
+  return invoke<void>('pty_write', { id, data })
+}
+
+export async function ptyResize(id: string, cols: number, rows: number): Promise<void> {
+  return invoke<void>('pty_resize', { id, cols, rows })
+}
+
+/** Kill the session's shell. Resolves false if it had already exited. */
+export async function ptyClose(id: string): Promise<boolean> {
+  return invoke<boolean>('pty_close', { id })
+}
+
+export function onPtyOutput(handler: (event: PtyOutputEvent) => void): Promise<UnlistenFn> {
+  return listen<PtyOutputEvent>('pty:output', (e) => handler(e.payload))
+}
+
+export function onPtyExit(handler: (event: PtyExitEvent) => void): Promise<UnlistenFn> {
+  return listen<PtyExitEvent>('pty:exit', (e) => handler(e.payload))
+}
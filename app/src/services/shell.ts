@@ -0,0 +1,109 @@
+/**
+ * shell — shell commands and background jobs (desktop only).
+ *
+ * `shellSpawn` starts `cmd` in the default shell (bash; PowerShell on
+ * Windows) in the Rust backend and resolves to a job id straight away. Pass
+ * your own `jobId` to subscribe before the job starts; early output is
+ * otherwise emitted before the id is known. Output
+ * arrives line by line through `shell:output` events and the end through
+ * `shell:exit`; a job can be fed stdin or killed while it runs. Use
+ * `shellRun` instead for short commands whose output is only needed once
+ * they finish.
+ *
+ * Agent commands run through `shellRun` are checked against the workspace's
+ * `shellPolicy`; those needing approval arrive as `shell:approval` events and
+ * wait (up to two minutes) for `shellApprove`.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+
+export interface ShellOutputEvent {
+  job_id: string
+  stream: 'stdout' | 'stderr'
+  line: string
+}
+
+export interface ShellRunOptions {
+  /** bash, sh, zsh, dash, fish, pwsh or powershell. Default: bash (PowerShell on Windows). */
+  shell?: string
+  /** Extra environment variables for the command. */
+  env?: Record<string, string>
+  /** Written to the command's stdin, which is then closed. */
+  stdin?: string
+  /** Cap on stdout + stderr together; longer streams keep their head and tail. Default 16000, 0 = no limit. */
+  maxOutputBytes?: number
+}
+
+export interface ShellRunResult {
+  stdout: string
+  stderr: string
+  exit_code: number
+  /** Which streams were cut to fit maxOutputBytes. */
+  truncated: { stdout: boolean; stderr: boolean }
+}
+
+export interface ShellApprovalEvent {
+  request_id: string
+  cmd: string
+  cwd: string
+  /** Why approval is needed, e.g. 'matches "sudo *"'. */
+  reason: string
+}
+
+export interface ShellExitEvent {
+  job_id: string
+  /** null when the job was killed. */
+  exit_code: number | null
+  killed: boolean
+  timed_out: boolean
+}
+
+/**
+ * Run `cmd` in `cwd` and wait for it. With `workspace`, cwd must be inside it
+ * and the run is audited; `agent` applies the workspace's shellPolicy.
+ */
+export async function shellRun(
+  cmd: string,
+  cwd: string,
+  opts: ShellRunOptions & { workspace?: string; agent?: boolean } = {},
+): Promise<ShellRunResult> {
+  const { workspace, agent, ...options } = opts
+  return invoke<ShellRunResult>('shell_run', { cmd, cwd, workspace: workspace ?? null, agent: agent ?? false, options })
+}
+
+/**
+ * Start `cmd` in `cwd` (must be inside the home directory). The job is
+ * killed after `timeoutSecs` — 10 minutes by default, 0 for no limit.
+ * @param jobId  Optional id for its events / shellKill; must not be running.
+ */
+export async function shellSpawn(cmd: string, cwd: string, timeoutSecs?: number, jobId?: string): Promise<string> {
+  return invoke<string>('shell_spawn', { cmd, cwd, timeoutSecs: timeoutSecs ?? null, jobId: jobId ?? null })
+}
+
+/** Kill a job and everything it started. Resolves false if it already exited. */
+export async function shellKill(jobId: string): Promise<boolean> {
+  return invoke<boolean>('shell_kill', { jobId })
+}
+
+/** Write to a job's stdin; `close` sends EOF afterwards. */
+export async function shellWriteStdin(jobId: string, data: string, close = false): Promise<void> {
+  return invoke<void>('shell_write_stdin', { jobId, data, close })
+}
+
+/** Answer a `shell:approval` request. Resolves false if it already expired. */
+export async function shellApprove(requestId: string, approved: boolean): Promise<boolean> {
+  return invoke<boolean>('shell_approve', { requestId, approved })
+}
+
+export function onShellOutput(handler: (event: ShellOutputEvent) => void): Promise<UnlistenFn> {
+  return listen<ShellOutputEvent>('shell:output', (e) => handler(e.payload))
+}
+
+export function onShellExit(handler: (event: ShellExitEvent) => void): Promise<UnlistenFn> {
+  return listen<ShellExitEvent>('shell:exit', (e) => handler(e.payload))
+}
+
+export function onShellApproval(handler: (event: ShellApprovalEvent) => void): Promise<UnlistenFn> {
+  return listen<ShellApprovalEvent>('shell:approval', (e) => handler(e.payload))
+}
@@ -18,6 +18,7 @@
  */
 
 import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
 import { documentDir } from '@tauri-apps/api/path'
 import type { Session, User } from '@supabase/supabase-js'
 import { supabase } from './supabase'
@@ -207,19 +208,39 @@ export function normalizeToHttps(url: string): string {
  * @param gitUrl  Remote URL (https)
  * @param token   Optional GitHub PAT / OAuth token for private repos
  * @param branch  Optional branch to checkout. Defaults to the remote’s default branch.
+ * @param opId    Optional id for `git:progress` events / gitCancel.
+ * @param keepSsh Keep an SSH URL as SSH (authenticated with the app's SSH key)
+ *                instead of rewriting it to HTTPS.
+ * @param tls     Optional certificate pins / CA file for a self-hosted server.
+ * @param provider Hosting provider; detected from the URL when omitted.
  */
-export async function gitClone(gitUrl: string, token?: string, branch?: string): Promise<string> {
+export async function gitClone(
+  gitUrl: string,
+  token?: string,
+  branch?: string,
+  opId?: string,
+  keepSsh = false,
+  tls?: GitTlsSettings,
+  provider?: GitProviderConfig,
+  partial?: GitPartialClone,
+): Promise<string> {
   // Normalize: strip trailing slash so we never get double //
   const docs = (await documentDir()).replace(/\/+$/, '')
   const name = repoNameFromUrl(gitUrl)
   const dest = `${docs}/${name}`
   // Normalize SSH → HTTPS so PAT token auth works on iOS (no SSH agent available)
-  const httpsUrl = normalizeToHttps(gitUrl)
+  // unless the caller opted into SSH key auth.
+  const cloneUrl = keepSsh ? gitUrl : normalizeToHttps(gitUrl)
   const result = await invoke<string>('git_clone', {
-    url: httpsUrl,
+    url: cloneUrl,
     path: dest,
     token: token ?? null,
     branch: branch ?? null,
+    keepSsh,
+    tls: tls ?? null,
+    provider: provider ?? null,
+    partial: partial ?? null,
+    opId: opId ?? null,
   })
   // 'already_cloned' means the directory already had a valid .git
   // If a specific branch was requested, ensure we're on it (handles the
@@ -248,18 +269,184 @@ export async function gitClone(gitUrl: string, token?: string, branch?: string):
 /**
  * Switch the already-cloned repo at `localPath` to `branch`, fetching from
  * origin first. Safe to call even if the branch is already the current one.
+ * @param mode 'reset' (default) points the branch at origin, dropping local
+ *             commits; 'safe' only fast-forwards and rejects if that would
+ *             lose unpushed work.
+ */
+export async function gitCheckoutBranch(
+  localPath: string,
+  branch: string,
+  token?: string,
+  mode?: 'reset' | 'safe',
+): Promise<void> {
+  await invoke<string>('git_checkout_branch', { path: localPath, branch, token: token ?? null, mode: mode ?? null })
+}
+
+/** A local or remote-tracking branch as returned by `git_list_branches`. */
+export interface GitBranch {
+  name: string
+  remote: boolean
+  current: boolean
+  upstream: string | null
+  ahead: number
+  behind: number
+  commit_id: string
+  summary: string
+}
+
+export async function gitListBranches(localPath: string): Promise<GitBranch[]> {
+  return invoke<GitBranch[]>('git_list_branches', { path: localPath })
+}
+
+/** Create `name` at `start` (HEAD by default), optionally switching to it. */
+export async function gitCreateBranch(
+  localPath: string,
+  name: string,
+  start?: string,
+  checkout?: boolean,
+): Promise<string> {
+  return invoke<string>('git_create_branch', { path: localPath, name, start: start ?? null, checkout: checkout ?? null })
+}
+
+/** Delete a local branch; rejects when it is not fully merged unless `force`. */
+export async function gitDeleteBranch(localPath: string, name: string, force?: boolean): Promise<string> {
+  return invoke<string>('git_delete_branch', { path: localPath, name, force: force ?? null })
+}
+
+/**
+ * Merge `branch` into the current one. Resolves 'up_to_date', 'fast_forwarded',
+ * 'merged' or 'conflicts' (finish with gitConflicts / gitResolveConflict).
  */
-export async function gitCheckoutBranch(localPath: string, branch: string, token?: string): Promise<void> {
-  await invoke<string>('git_checkout_branch', { path: localPath, branch, token: token ?? null })
+export async function gitMergeBranch(localPath: string, branch: string): Promise<string> {
+  return invoke<string>('git_merge_branch', { path: localPath, branch })
 }
 
 /**
  * Pull latest changes on the already-cloned repo at `localPath`.
  * @param localPath Absolute path previously returned by gitClone
  * @param token     Optional GitHub PAT / OAuth token
+ * @param opId      Optional id for `git:progress` events / gitCancel.
+ */
+export async function gitPull(localPath: string, token?: string, opId?: string): Promise<string> {
+  return invoke<string>('git_pull', { path: localPath, token: token ?? null, opId: opId ?? null })
+}
+
+/** How much history and which folders a clone keeps (see gitClone). */
+export interface GitPartialClone {
+  /** Keep only the last N commits; null = full history. */
+  depth: number | null
+  /**
+   * Check out only these folders (plus top-level files); empty = everything.
+   * Commits still include the folders that are left out.
+   */
+  sparse_paths: string[]
+}
+
+export async function gitGetPartial(localPath: string): Promise<GitPartialClone> {
+  return invoke<GitPartialClone>('git_get_partial', { path: localPath })
+}
+
+/**
+ * Fetch more history for a shallow clone: `depth` commits from the tip in
+ * total (more than it has now), or all of it when omitted. Resolves to
+ * 'deepened' or 'complete' (nothing to fetch).
+ */
+export async function gitDeepen(localPath: string, token?: string, depth?: number, opId?: string): Promise<string> {
+  return invoke<string>('git_deepen', {
+    path: localPath,
+    token: token ?? null,
+    depth: depth ?? null,
+    opId: opId ?? null,
+  })
+}
+
+/** Change which folders are checked out; an empty list checks out everything. */
+export async function gitSetSparse(localPath: string, paths: string[], token?: string): Promise<string> {
+  return invoke<string>('git_set_sparse', { path: localPath, paths, token: token ?? null })
+}
+
+/** Payload of the `git:progress` event emitted while clone / pull / sync / tag push / deepen run. */
+export interface GitProgressEvent {
+  op_id: string
+  operation: 'clone' | 'pull' | 'sync' | 'push' | 'fetch'
+  phase: 'counting' | 'compressing' | 'receiving' | 'resolving' | 'writing' | 'checkout'
+  current: number
+  total: number
+  /** Bytes transferred so far (receiving / writing only). */
+  bytes: number
+}
+
+/** Subscribe to progress of in-flight git network operations. */
+export function onGitProgress(handler: (event: GitProgressEvent) => void): Promise<UnlistenFn> {
+  return listen<GitProgressEvent>('git:progress', (e) => handler(e.payload))
+}
+
+/**
+ * Abort the clone / pull / sync started with `opId`; it then rejects with
+ * "cancelled". Resolves false if that operation is no longer running.
+ */
+export async function gitCancel(opId: string): Promise<boolean> {
+  return invoke<boolean>('git_cancel', { opId })
+}
+
+/** One conflict region, parsed from the diff3 markers git left in the file. */
+export interface GitConflictHunk {
+  start_line: number
+  end_line: number
+  ours: string
+  base: string | null
+  theirs: string
+}
+
+export interface GitConflictFile {
+  path: string
+  hunks: GitConflictHunk[]
+  /** No markers (binary or modify/delete) — only whole-file ours/theirs applies. */
+  whole_file: boolean
+}
+
+export type GitHunkChoice = 'ours' | 'theirs' | 'base' | 'both'
+
+export type GitConflictResolution =
+  | 'ours'
+  | 'theirs'
+  | { hunks: GitHunkChoice[] }
+  | { content: string }
+
+/** Files left conflicted by a `gitPull` that returned `'conflicts'`. */
+export async function gitConflicts(localPath: string): Promise<GitConflictFile[]> {
+  return invoke<GitConflictFile[]>('git_conflicts', { path: localPath })
+}
+
+/**
+ * Resolve one conflicted file. Returns `'merged'` once the last conflict is
+ * resolved (the merge commit is created), otherwise `'resolved'`.
  */
-export async function gitPull(localPath: string, token?: string): Promise<string> {
-  return invoke<string>('git_pull', { path: localPath, token: token ?? null })
+export async function gitResolveConflict(
+  localPath: string,
+  file: string,
+  resolution: GitConflictResolution,
+): Promise<string> {
+  return invoke<string>('git_resolve_conflict', { path: localPath, file, resolution })
+}
+
+/** Abandon an in-progress merge and restore the pre-pull state. */
+export async function gitMergeAbort(localPath: string): Promise<string> {
+  return invoke<string>('git_merge_abort', { path: localPath })
+}
+
+/** Structured result of the Rust `git_sync` command. */
+export interface SyncOutcome {
+  commit: 'committed' | 'nothing_to_commit'
+  commit_id: string | null
+  push: 'pushed' | 'rejected_non_fast_forward' | 'auth_failed' | 'offline' | 'certificate_invalid' | 'no_remote' | 'failed'
+  /** Raw git error text when the push did not succeed. */
+  push_error: string | null
+  /** Result of the automatic pull after a rejected push (e.g. 'merged', 'conflicts'). */
+  pull: string | null
+  /** Commits ahead of / behind origin after the sync. */
+  ahead: number
+  behind: number
 }
 
 /**
@@ -268,21 +455,106 @@ export async function gitPull(localPath: string, token?: string): Promise<string
  * @param localPath Absolute path to the local repo
  * @param token     Optional GitHub PAT / OAuth token
  * @param message   Commit message. Defaults to a timestamped “Sync from mobile” message.
+ * @param paths     Optional paths or globs to stage. Defaults to every change
+ *                  not excluded by the workspace `syncIgnore` list.
  */
 export async function gitSync(
   localPath: string,
   token?: string,
   message?: string,
-): Promise<string> {
+  paths?: string[],
+): Promise<SyncOutcome> {
   const msg = message ?? `Sync from mobile — ${new Date().toLocaleString('pt-BR')}`
-  // 1. Pull latest first (fast-forward only; ignore “up_to_date”)
+  // 1. Pull latest first (merges diverged history; ignore “up_to_date”)
   try {
     await gitPull(localPath, token)
   } catch {
     // Pull failed (e.g. conflicts) — still try to push local changes
   }
   // 2. Commit + push via Rust git_sync (token enables HTTPS push, URL normalized in Rust)
-  return invoke<string>('git_sync', { path: localPath, message: msg, token: token ?? null })
+  return invoke<SyncOutcome>('git_sync', { path: localPath, message: msg, token: token ?? null, paths: paths ?? null })
+}
+
+/** Payload of the `sync:status` event emitted by the Rust auto-sync scheduler. */
+export interface AutoSyncStatus {
+  path: string
+  state: 'disabled' | 'idle' | 'pending' | 'syncing' | 'pulling' | 'offline' | 'error'
+  /** Changed files (minus `syncIgnore`) at the last poll. */
+  changes: number
+  /** Unix seconds of the last background sync or pull that reached the remote. */
+  last_sync: number | null
+  /** Unix seconds of the next attempt while backing off. */
+  retry_at: number | null
+  outcome: SyncOutcome | null
+  /** Result of the last background pull ('up_to_date', 'pulled', 'merged', 'conflicts'). */
+  pull: string | null
+  error: string | null
+}
+
+/**
+ * Start the background auto-sync scheduler for a workspace (configured by
+ * `autoSync` in cafezin/config.json). It runs in the Rust backend and keeps
+ * going across webview reloads; calling this again only refreshes the token.
+ */
+export async function autoSyncStart(localPath: string, token?: string): Promise<AutoSyncStatus> {
+  return invoke<AutoSyncStatus>('auto_sync_start', { path: localPath, token: token ?? null })
+}
+
+/** Stop the scheduler for a workspace. Resolves false if none was running. */
+export async function autoSyncStop(localPath: string): Promise<boolean> {
+  return invoke<boolean>('auto_sync_stop', { path: localPath })
+}
+
+/** Current scheduler status, or null when no scheduler runs for the workspace. */
+export async function autoSyncStatus(localPath: string): Promise<AutoSyncStatus | null> {
+  return invoke<AutoSyncStatus | null>('auto_sync_status', { path: localPath })
+}
+
+/** Subscribe to auto-sync state changes of every running scheduler. */
+export function onSyncStatus(handler: (status: AutoSyncStatus) => void): Promise<UnlistenFn> {
+  return listen<AutoSyncStatus>('sync:status', (e) => handler(e.payload))
+}
+
+/** A branch whose commits git_sync could not push yet (see `syncQueueStatus`). */
+export interface PushQueueItem {
+  path: string
+  branch: string
+  /** Local commits origin does not have yet. */
+  commits: number
+  /** Unix seconds the branch was first queued. */
+  queued_at: number
+  attempts: number
+  /** Unix seconds of the next automatic retry. */
+  next_retry: number
+  last_state: SyncOutcome['push']
+  last_error: string | null
+}
+
+export interface SyncQueueStatus {
+  /** Total commits waiting to upload, e.g. for "3 commits waiting to upload". */
+  commits: number
+  items: PushQueueItem[]
+}
+
+/**
+ * Offline push queue kept by the Rust backend: branches committed by git_sync
+ * whose push failed. They are retried with exponential backoff, also across restarts.
+ */
+export async function syncQueueStatus(localPath?: string): Promise<SyncQueueStatus> {
+  return invoke<SyncQueueStatus>('sync_queue_status', { path: localPath ?? null })
+}
+
+/**
+ * Retry queued pushes now (e.g. on the browser `online` event) instead of
+ * waiting out the backoff. Resolves to the number of branches rescheduled.
+ */
+export async function syncQueueRetry(localPath?: string, token?: string): Promise<number> {
+  return invoke<number>('sync_queue_retry', { path: localPath ?? null, token: token ?? null })
+}
+
+/** Subscribe to changes of the push queue (the full queue is delivered each time). */
+export function onSyncQueue(handler: (items: PushQueueItem[]) => void): Promise<UnlistenFn> {
+  return listen<PushQueueItem[]>('sync:queue', (e) => handler(e.payload))
 }
 
 /**
@@ -417,14 +689,16 @@ export function listGitAccountLabels(): string[] {
 
 /**
  * Start device-flow OAuth for a specific named git account (e.g. "personal").
- * Requests `repo` scope for clone/pull/push access.
+ * Requests `repo` scope for clone/pull/push access — GitHub's built-in app
+ * unless `provider` names another host (GitLab, GitHub Enterprise).
  * Separate from Cafezin account auth — these tokens are for git operations only.
  */
 export async function startGitAccountFlow(
   label: string,
   onState: (state: SyncDeviceFlowState) => void,
+  provider?: GitProviderConfig,
 ): Promise<string> {
-  const token = await runDeviceFlow('repo', onState)
+  const token = await runDeviceFlow('repo', onState, provider)
   storeGitAccountToken(label, token)
   return token
 }
@@ -452,15 +726,12 @@ function waitOrResume(ms: number): Promise<void> {
 async function runDeviceFlow(
   scope: string,
   onState: (state: SyncDeviceFlowState) => void,
+  provider?: GitProviderConfig,
 ): Promise<string> {
   // Step 1: init — credentials stay in Rust
-  const d = await invoke<{
-    device_code: string
-    user_code: string
-    verification_uri: string
-    expires_in: number
-    interval: number
-  }>('github_device_flow_init', { scope })
+  const d = provider
+    ? await gitProviderDeviceFlowInit(provider, scope)
+    : await invoke<DeviceFlowInit>('github_device_flow_init', { scope })
 
   const intervalMs = (d.interval + 1) * 1000
   const expiresAt = Date.now() + d.expires_in * 1000
@@ -469,11 +740,9 @@ async function runDeviceFlow(
   // Step 2: poll until authorized or timed out
   while (Date.now() < expiresAt) {
     await waitOrResume(intervalMs)
-    const poll = await invoke<{
-      access_token?: string
-      error?: string
-      error_description?: string
-    }>('github_device_flow_poll', { deviceCode: d.device_code })
+    const poll = provider
+      ? await gitProviderDeviceFlowPoll(provider, d.device_code)
+      : await invoke<DeviceFlowPoll>('github_device_flow_poll', { deviceCode: d.device_code })
     if (poll.access_token) return poll.access_token
     if (poll.error === 'slow_down') { await waitOrResume(3000); continue }
     if (poll.error === 'authorization_pending') continue
@@ -482,3 +751,159 @@ async function runDeviceFlow(
   throw new Error('Device flow timed out — please try again')
 }
 
+// ── Git hosting providers ─────────────────────────────────────────────────────
+
+export type GitProviderKind = 'github' | 'gitlab' | 'gitea' | 'generic'
+
+/** Which host a repo talks to; stored in the repo's local git config. */
+export interface GitProviderConfig {
+  kind: GitProviderKind
+  /** Web root of a self-hosted instance (e.g. https://git.example.org). */
+  base_url?: string | null
+  /** Basic-auth username for the generic provider. */
+  username?: string | null
+  /** OAuth application used for the device flow (GitLab, GitHub Enterprise). */
+  client_id?: string | null
+}
+
+/** First step of an OAuth device flow: the code to show and how to poll. */
+export interface DeviceFlowInit {
+  device_code: string
+  user_code: string
+  verification_uri: string
+  expires_in: number
+  interval: number
+}
+
+/** A poll answer: a token once authorized, else an RFC 8628 error code. */
+export interface DeviceFlowPoll {
+  access_token?: string
+  error?: string
+  error_description?: string
+}
+
+export interface CreateRepoRequest {
+  name: string
+  description?: string | null
+  private?: boolean
+  /** Organisation / group; the token's own account when omitted. */
+  owner?: string | null
+}
+
+export interface CreatedRepo {
+  full_name: string
+  clone_url: string
+  ssh_url: string | null
+  html_url: string
+  default_branch: string | null
+  private: boolean
+}
+
+/** The repo's stored provider, or one detected from its origin URL. */
+export async function gitGetProvider(localPath: string): Promise<GitProviderConfig> {
+  return invoke<GitProviderConfig>('git_get_provider', { path: localPath })
+}
+
+/** Store the repo's provider; null goes back to detecting it from origin. */
+export async function gitSetProvider(localPath: string, provider: GitProviderConfig | null): Promise<string> {
+  return invoke<string>('git_set_provider', { path: localPath, provider })
+}
+
+/**
+ * Publish a local workspace to a new GitHub repository: creates the repo, sets
+ * `origin` and pushes (committing pending changes first). `token` is the one
+ * from the GitHub device flow (needs the "repo" scope). Once the repository
+ * exists this resolves: a failed push is reported in `sync.push`, a local
+ * failure before it (init, remote, commit) in `sync_error` with `sync` null.
+ */
+export async function githubCreateRepo(
+  localPath: string,
+  name: string,
+  isPrivate: boolean,
+  token: string,
+  opId?: string,
+): Promise<{ repo: CreatedRepo; sync: SyncOutcome | null; sync_error: string | null }> {
+  return invoke('github_create_repo', { path: localPath, name, private: isPrivate, token, opId: opId ?? null })
+}
+
+/** Start the device flow against `provider` (GitLab and GitHub Enterprise need its client_id). */
+export async function gitProviderDeviceFlowInit(provider: GitProviderConfig, scope: string): Promise<DeviceFlowInit> {
+  return invoke<DeviceFlowInit>('git_provider_device_flow_init', { provider, scope })
+}
+
+export async function gitProviderDeviceFlowPoll(provider: GitProviderConfig, deviceCode: string): Promise<DeviceFlowPoll> {
+  return invoke<DeviceFlowPoll>('git_provider_device_flow_poll', { provider, deviceCode })
+}
+
+export async function gitProviderCreateRepo(provider: GitProviderConfig, token: string, request: CreateRepoRequest): Promise<CreatedRepo> {
+  return invoke<CreatedRepo>('git_provider_create_repo', { provider, token, request })
+}
+
+// ── Server trust (self-hosted git servers) ───────────────────────────────────
+
+/** Certificate overrides stored in the repo's local git config. */
+export interface GitTlsSettings {
+  /** SHA-256 fingerprints (hex) of the accepted certificate / SSH host key. */
+  pinned_sha256: string[]
+  /** PEM file with extra CA certificates. */
+  ca_file: string | null
+}
+
+export async function gitGetTls(localPath: string): Promise<GitTlsSettings> {
+  return invoke<GitTlsSettings>('git_get_tls', { path: localPath })
+}
+
+export async function gitSetTls(localPath: string, tls: GitTlsSettings): Promise<string> {
+  return invoke<string>('git_set_tls', { path: localPath, tls })
+}
+
+/**
+ * Commit author and signing for a workspace, stored under git's own config
+ * keys (user.name, user.email, commit.gpgSign, gpg.format, user.signingKey).
+ */
+export interface GitIdentity {
+  name: string | null
+  email: string | null
+  /** Sign every commit; null leaves them unsigned. 'openpgp' needs the desktop (git CLI) build. */
+  signing: 'ssh' | 'openpgp' | null
+  /**
+   * SSH: key file (or "key::<public key>"); App Store / iOS builds use the
+   * app's own key when null. GPG: key id; null = gpg's default key.
+   */
+  signing_key: string | null
+}
+
+export async function gitGetIdentity(localPath: string): Promise<GitIdentity> {
+  return invoke<GitIdentity>('git_get_identity', { path: localPath })
+}
+
+export async function gitSetIdentity(localPath: string, identity: GitIdentity): Promise<string> {
+  return invoke<string>('git_set_identity', { path: localPath, identity })
+}
+
+// ── Git LFS ───────────────────────────────────────────────────────────────────
+
+/**
+ * Store files with these extensions (default: common image, video, audio and
+ * PDF types) in Git LFS from the next sync on. Returns all tracked patterns.
+ */
+export async function gitLfsTrack(localPath: string, extensions?: string[]): Promise<string[]> {
+  return invoke<string[]>('git_lfs_track', { path: localPath, extensions: extensions ?? null })
+}
+
+/** Patterns (e.g. "*.png") the workspace stores in Git LFS. */
+export async function gitLfsTracked(localPath: string): Promise<string[]> {
+  return invoke<string[]>('git_lfs_tracked', { path: localPath })
+}
+
+// ── SSH keys (App Store / iOS) ────────────────────────────────────────────────
+
+/** Generate the app's Ed25519 key; returns the public key to add to the git host. */
+export async function sshGenerateKey(comment?: string, overwrite = false): Promise<string> {
+  return invoke<string>('ssh_generate_key', { comment: comment ?? null, overwrite })
+}
+
+/** The app's public SSH key, or null if none has been generated. */
+export async function sshPublicKey(): Promise<string | null> {
+  return invoke<string | null>('ssh_public_key')
+}
@@ -11,80 +11,75 @@ import {
 } from './fs';
 import { invoke } from '@tauri-apps/api/core';
 import type { Workspace, WorkspaceConfig, RecentWorkspace, FileTreeNode } from '../types';
-import { CONFIG_DIR, WORKSPACE_SKIP } from './config';
+import { CONFIG_DIR } from './config';
 
 const RECENTS_KEY = 'cafezin-recent-workspaces';
 const CONFIG_FILE = 'config.json';
 const AGENT_FILE = 'AGENT.md';
 
+/** Result of the Rust `workspace_tree` command. */
+interface WorkspaceTree {
+  nodes: FileTreeNode[];
+  /** Something was left out (too deep or too many files). */
+  truncated: boolean;
+}
+
 /**
- * Recursively build a file tree starting at `dirAbsPath`.
- * `relBase` is the prefix to prepend to child paths (relative to workspace root).
+ * List the workspace with the Rust `workspace_tree` command, which walks in
+ * parallel and skips WORKSPACE_TREE_SKIP, hidden entries and whatever `.gitignore`
+ * / `cafezin/ignore` exclude. Throws if the folder cannot be listed.
  */
-async function buildFileTree(
-  dirAbsPath: string,
-  relBase: string = '',
-  depth = 0,
-): Promise<FileTreeNode[]> {
-  if (depth > 8) return [];
-  let entries;
-  try {
-    entries = await readDir(dirAbsPath);
-  } catch {
-    return [];
-  }
-
-  const nodes: FileTreeNode[] = [];
-  for (const entry of entries) {
-    if (!entry.name) continue;
-    if (WORKSPACE_SKIP.has(entry.name)) continue;
-    if (entry.name.startsWith('.')) continue;
-
-    const relPath = relBase ? `${relBase}/${entry.name}` : entry.name;
-    if (entry.isDirectory) {
-      const children = await buildFileTree(`${dirAbsPath}/${entry.name}`, relPath, depth + 1);
-      nodes.push({ name: entry.name, path: relPath, isDirectory: true, children });
-    } else {
-      nodes.push({ name: entry.name, path: relPath, isDirectory: false });
-    }
-  }
+function workspaceTree(workspacePath: string): Promise<WorkspaceTree> {
+  return invoke<WorkspaceTree>('workspace_tree', { path: workspacePath });
+}
 
+/** Directories first, then by name in the user's locale, ignoring case and accents. */
+function sortFileTree(nodes: FileTreeNode[]): FileTreeNode[] {
+  for (const node of nodes) if (node.children) sortFileTree(node.children);
   return nodes.sort((a, b) => {
-    // Directories first, then alphabetical
     if (a.isDirectory !== b.isDirectory) return a.isDirectory ? -1 : 1;
     return a.name.localeCompare(b.name, undefined, { sensitivity: 'base' });
   });
 }
 
 /**
- * Recursively walk a directory and return a flat, sorted list of relative file
- * paths, applying the standard workspace skip rules (WORKSPACE_SKIP, hidden
- * entries starting with `.`).
+ * Build the workspace file tree. When it is incomplete, `fileTreeTruncated`
+ * is set and the directories that were not (fully) listed carry
+ * `truncated: true`, so the sidebar can say so.
+ */
+async function buildFileTree(workspacePath: string): Promise<{ fileTree: FileTreeNode[]; fileTreeTruncated: boolean }> {
+  try {
+    const tree = await workspaceTree(workspacePath);
+    return { fileTree: sortFileTree(tree.nodes), fileTreeTruncated: tree.truncated };
+  } catch (e) {
+    console.warn('[workspace] workspace_tree failed:', e);
+    return { fileTree: [], fileTreeTruncated: false };
+  }
+}
+
+/**
+ * Return a flat, sorted list of the workspace's file paths (relative), with
+ * the same skip rules as the file tree: WORKSPACE_TREE_SKIP, hidden entries,
+ * `.gitignore` and `cafezin/ignore`.
  *
- * @param dir     Absolute path of the directory to walk.
- * @param rel     Prefix to prepend to each relative path (used internally for recursion).
- * @param filter  Optional predicate `(relPath, ext) => boolean`.
- *                When provided, only files for which the predicate returns `true`
- *                are included.  Directories are always traversed regardless.
+ * @param workspacePath  Absolute path of the workspace.
+ * @param filter         Optional predicate `(relPath, ext) => boolean`.
+ *                       When provided, only files for which the predicate
+ *                       returns `true` are included.
  */
 export async function walkFilesFlat(
-  dir: string,
-  rel = '',
+  workspacePath: string,
   filter?: (relPath: string, ext: string) => boolean,
 ): Promise<string[]> {
-  let entries;
-  try { entries = await readDir(dir); } catch { return []; }
+  let tree: WorkspaceTree;
+  try { tree = await workspaceTree(workspacePath); } catch { return []; }
   const paths: string[] = [];
-  for (const e of entries) {
-    if (!e.name || WORKSPACE_SKIP.has(e.name) || e.name.startsWith('.')) continue;
-    const relPath = rel ? `${rel}/${e.name}` : e.name;
-    if (e.isDirectory) {
-      paths.push(...await walkFilesFlat(`${dir}/${e.name}`, relPath, filter));
-    } else {
-      const ext = e.name.split('.').pop()?.toLowerCase() ?? '';
-      if (!filter || filter(relPath, ext)) paths.push(relPath);
-    }
+  function walk(n: FileTreeNode) {
+    if (n.isDirectory) { n.children?.forEach(walk); return; }
+    const ext = n.name.split('.').pop()?.toLowerCase() ?? '';
+    if (!filter || filter(n.path, ext)) paths.push(n.path);
   }
+  tree.nodes.forEach(walk);
   return paths.sort();
 }
 
@@ -147,7 +142,7 @@ export async function loadWorkspace(folderPath: string): Promise<Workspace> {
   } catch { /* no remote = local only */ }
 
   // 5. Build full recursive file tree (and derive .md file list from it)
-  const fileTree = await buildFileTree(folderPath);
+  const { fileTree, fileTreeTruncated } = await buildFileTree(folderPath);
   const files = flatMdFiles(fileTree);
 
   const workspace: Workspace = {
@@ -157,6 +152,7 @@ export async function loadWorkspace(folderPath: string): Promise<Workspace> {
     agentContext,
     files,
     fileTree,
+    fileTreeTruncated,
     hasGit,
   };
 
@@ -474,7 +470,9 @@ export function flatMdFiles(nodes: FileTreeNode[]): string[] {
 }
 
 /** Rebuild the full file tree for a workspace (call after creating/deleting files). */
-export async function refreshFileTree(workspace: Workspace): Promise<FileTreeNode[]> {
+export async function refreshFileTree(
+  workspace: Workspace,
+): Promise<{ fileTree: FileTreeNode[]; fileTreeTruncated: boolean }> {
   return buildFileTree(workspace.path);
 }
 
@@ -483,9 +481,9 @@ export async function refreshFileTree(workspace: Workspace): Promise<FileTreeNod
  */
 export async function refreshWorkspaceFiles(
   workspace: Workspace,
-): Promise<{ files: string[]; fileTree: FileTreeNode[] }> {
-  const fileTree = await buildFileTree(workspace.path);
-  return { files: flatMdFiles(fileTree), fileTree };
+): Promise<{ files: string[]; fileTree: FileTreeNode[]; fileTreeTruncated: boolean }> {
+  const { fileTree, fileTreeTruncated } = await buildFileTree(workspace.path);
+  return { files: flatMdFiles(fileTree), fileTree, fileTreeTruncated };
 }
 
 // ── Recents ──────────────────────────────────────────────────
@@ -223,6 +223,39 @@ export interface WorkspaceConfig {
    * Set on desktop via Settings → Workspace. Mobile uses this branch when cloning/pulling.
    */
   gitBranch?: string;
+  /**
+   * Glob patterns never staged by git sync (e.g. "drafts/", "*.tmp").
+   * A pattern without "/" matches a file or folder name at any depth, like .gitignore.
+   */
+  syncIgnore?: string[];
+  /** Background sync run by the Rust scheduler (see autoSyncStart). Off by default. */
+  autoSync?: AutoSyncConfig;
+  /** Which commands the AI agent may run (desktop). See ShellPolicyConfig. */
+  shellPolicy?: ShellPolicyConfig;
+}
+
+export interface AutoSyncConfig {
+  enabled?: boolean;
+  /** Seconds without further edits before changes are committed and pushed (default 30). */
+  idleSeconds?: number;
+  /** Seconds between background pulls; 0 disables them (default 300). */
+  pullIntervalSeconds?: number;
+  /** Upper bound for the retry delay while offline (default 900). */
+  maxBackoffSeconds?: number;
+}
+
+/**
+ * Patterns use `*` and `?`, and one without a trailing `*` also matches the
+ * command with more arguments ("git push" covers "git push origin main").
+ * Every command chained with `&&`, `|`, `;` … is checked on its own.
+ */
+export interface ShellPolicyConfig {
+  /** When non-empty, any other command needs the user's approval. */
+  allow?: string[];
+  /** Always refused. */
+  deny?: string[];
+  /** Run only after the user approves (default: sudo, rm -r, force push, reset --hard, git clean). */
+  confirm?: string[];
 }
 
 /** A span of text inserted by the AI and not yet reviewed by the human. */
@@ -248,6 +281,8 @@ export interface Workspace {
   agentContext?: string; // contents of AGENT.md if present
   files: string[];       // .md filenames (relative) – kept for compat
   fileTree: FileTreeNode[]; // full recursive tree of the workspace
+  /** Some of the workspace is missing from fileTree (too deep or too many files). */
+  fileTreeTruncated?: boolean;
   /** True when the workspace folder has a git remote configured (origin). */
   hasGit: boolean;
 }
@@ -270,6 +305,12 @@ export interface FileTreeNode {
   path: string;
   isDirectory: boolean;
   children?: FileTreeNode[];
+  /** File size in bytes (files only). */
+  size?: number;
+  /** Last modification, ms since the epoch. */
+  modified?: number;
+  /** Directory whose contents were not (all) listed (too deep, or too many files). */
+  truncated?: boolean;
 }
 
 export type CopilotModel = string; // resolved dynamically from /models endpoint
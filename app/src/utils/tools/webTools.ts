@@ -365,7 +365,7 @@ export const executeWebTools: DomainExecutor = async (name, args, ctx) => {
       try {
         const result = await invoke<{ stdout: string; stderr: string; exit_code: number }>(
           'shell_run',
-          { cmd: command, cwd: absCwd },
+          { cmd: command, cwd: absCwd, workspace: workspacePath, agent: true },
         );
         emitTerminalEntry({
           source: 'ai',